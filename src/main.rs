@@ -6,23 +6,92 @@ use axum::{
 };
 use base58::{FromBase58, ToBase58};
 use base64::{engine::general_purpose, Engine};
+use mpl_token_metadata::instruction::create_metadata_accounts_v3;
+use mpl_token_metadata::ID as METADATA_PROGRAM_ID;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
+    hash::Hash,
     instruction::{AccountMeta, Instruction},
     message::Message,
+    program_pack::Pack,
     pubkey::Pubkey,
+    rent::Rent,
     signature::{Keypair, Signature, Signer},
     system_instruction,
+    system_instruction::SystemInstruction,
+    system_program,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
 };
 use spl_token::instruction as token_instruction;
+use spl_token::instruction::TokenInstruction;
+use spl_token::state::{Account as TokenAccountState, Mint as MintState};
 use spl_token::ID as TOKEN_PROGRAM_ID;
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 #[derive(Clone)]
-struct AppState;
+struct AppState {
+    rpc_url: Option<String>,
+}
+
+/// Fetches an account via the configured RPC endpoint and confirms it is owned by the
+/// SPL Token program, mirroring `assert_owned_by` from Solana's program-test helpers.
+async fn assert_owned_by_token_program(
+    client: &RpcClient,
+    pubkey: &Pubkey,
+) -> Result<solana_sdk::account::Account, Json<ErrorResponse>> {
+    let account = client
+        .get_account(pubkey)
+        .await
+        .map_err(|_| error("account not found"))?;
+    if account.owner != TOKEN_PROGRAM_ID {
+        return Err(error("account not owned by token program"));
+    }
+    Ok(account)
+}
+
+/// Mirrors `assert_initialized` for mint accounts.
+async fn assert_initialized_mint(
+    client: &RpcClient,
+    mint: &Pubkey,
+) -> Result<(), Json<ErrorResponse>> {
+    let account = assert_owned_by_token_program(client, mint).await?;
+    let mint_state = MintState::unpack(&account.data).map_err(|_| error("mint not initialized"))?;
+    if !mint_state.is_initialized {
+        return Err(error("mint not initialized"));
+    }
+    Ok(())
+}
+
+/// Mirrors `assert_initialized` for token accounts.
+async fn assert_initialized_token_account(
+    client: &RpcClient,
+    token_account: &Pubkey,
+) -> Result<(), Json<ErrorResponse>> {
+    let account = assert_owned_by_token_program(client, token_account).await?;
+    let token_account_state = TokenAccountState::unpack(&account.data)
+        .map_err(|_| error("token account not initialized"))?;
+    if !token_account_state.is_initialized() {
+        return Err(error("token account not initialized"));
+    }
+    Ok(())
+}
+
+/// Mirrors `assert_rent_exempt`: checks that an account's lamport balance covers the
+/// rent-exemption minimum for the given data length.
+fn assert_rent_exempt(lamports: u64, data_len: usize) -> Result<(), Json<ErrorResponse>> {
+    let rent = Rent::default();
+    if !rent.is_exempt(lamports, data_len) {
+        return Err(error("account is not rent exempt"));
+    }
+    Ok(())
+}
 
 #[derive(Serialize)]
 struct SuccessResponse<T> {
@@ -47,6 +116,40 @@ async fn hello_world() -> &'static str {
     "Hello, world!"
 }
 
+fn instruction_to_json(ix: &Instruction) -> Value {
+    serde_json::json!({
+        "program_id": ix.program_id.to_string(),
+        "accounts": ix.accounts,
+        "instruction_data": general_purpose::STANDARD.encode(&ix.data),
+    })
+}
+
+/// Splits the caller-supplied keypairs into those that match a required signer and those
+/// that don't (dropped silently), and reports which required signers remain unmatched.
+/// Over-supplying unrelated secrets (e.g. a wallet's whole keyset) must not fail a
+/// transaction that would otherwise have signed fine.
+fn partition_known_signers(
+    keypairs: Vec<Keypair>,
+    required_signers: &[String],
+) -> (Vec<Keypair>, Vec<String>) {
+    let known_signers: Vec<Keypair> = keypairs
+        .into_iter()
+        .filter(|k| required_signers.contains(&k.pubkey().to_string()))
+        .collect();
+
+    let signing_keys: Vec<String> = known_signers
+        .iter()
+        .map(|k| k.pubkey().to_string())
+        .collect();
+    let missing_signers: Vec<String> = required_signers
+        .iter()
+        .filter(|pk| !signing_keys.contains(pk))
+        .cloned()
+        .collect();
+
+    (known_signers, missing_signers)
+}
+
 // 1. Generate Keypair
 async fn generate_keypair() -> Json<impl Serialize> {
     let keypair = Keypair::new();
@@ -65,20 +168,41 @@ struct CreateTokenReq {
     mint_authority: String,
     mint: String,
     decimals: u8,
+    freeze_authority: Option<String>,
+    #[serde(default)]
+    validate: bool,
 }
 
 async fn create_token(
+    State(state): State<Arc<AppState>>,
     Json(req): Json<CreateTokenReq>,
 ) -> Result<Json<SuccessResponse<serde_json::Value>>, Json<ErrorResponse>> {
     let mint = Pubkey::from_str(&req.mint).map_err(|_| error("Invalid mint pubkey"))?;
     let mint_authority = Pubkey::from_str(&req.mint_authority)
         .map_err(|_| error("Invalid mint authority pubkey"))?;
+    let freeze_authority = req
+        .freeze_authority
+        .as_deref()
+        .map(Pubkey::from_str)
+        .transpose()
+        .map_err(|_| error("Invalid freeze authority pubkey"))?;
+
+    if req.validate {
+        if let Some(rpc_url) = &state.rpc_url {
+            let client = RpcClient::new(rpc_url.clone());
+            let account = client
+                .get_account(&mint)
+                .await
+                .map_err(|_| error("account not found"))?;
+            assert_rent_exempt(account.lamports, MintState::LEN)?;
+        }
+    }
 
     let ix = token_instruction::initialize_mint(
         &TOKEN_PROGRAM_ID,
         &mint,
         &mint_authority,
-        None,
+        freeze_authority.as_ref(),
         req.decimals,
     )
     .map_err(|_| error("Failed to create initialize_mint instruction"))?;
@@ -100,9 +224,12 @@ struct MintTokenReq {
     destination: String,
     authority: String,
     amount: u64,
+    #[serde(default)]
+    validate: bool,
 }
 
 async fn mint_token(
+    State(state): State<Arc<AppState>>,
     Json(req): Json<MintTokenReq>,
 ) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
     let mint = Pubkey::from_str(&req.mint).map_err(|_| error("Invalid mint pubkey"))?;
@@ -110,6 +237,14 @@ async fn mint_token(
         Pubkey::from_str(&req.destination).map_err(|_| error("Invalid destination pubkey"))?;
     let auth = Pubkey::from_str(&req.authority).map_err(|_| error("Invalid authority pubkey"))?;
 
+    if req.validate {
+        if let Some(rpc_url) = &state.rpc_url {
+            let client = RpcClient::new(rpc_url.clone());
+            assert_initialized_mint(&client, &mint).await?;
+            assert_initialized_token_account(&client, &dest).await?;
+        }
+    }
+
     let ix = token_instruction::mint_to(&TOKEN_PROGRAM_ID, &mint, &dest, &auth, &[], req.amount)
         .map_err(|_| error("Failed to create mint_to instruction"))?;
 
@@ -171,7 +306,6 @@ async fn verify_message(
 
     // let valid = Signature::verify(&signature, pubkey.as_ref(), req.message.as_bytes()).is_ok();
 
-
     Ok(Json(SuccessResponse {
         success: true,
         data: serde_json::json!({
@@ -215,9 +349,12 @@ struct SendTokenReq {
     mint: String,
     owner: String,
     amount: u64,
+    #[serde(default)]
+    validate: bool,
 }
 
 async fn send_token(
+    State(state): State<Arc<AppState>>,
     Json(req): Json<SendTokenReq>,
 ) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
     let dest =
@@ -225,8 +362,27 @@ async fn send_token(
     let mint = Pubkey::from_str(&req.mint).map_err(|_| error("Invalid mint pubkey"))?;
     let owner = Pubkey::from_str(&req.owner).map_err(|_| error("Invalid owner pubkey"))?;
 
-    let ix = token_instruction::transfer(&TOKEN_PROGRAM_ID, &mint, &dest, &owner, &[], req.amount)
-        .map_err(|_| error("Failed to create transfer instruction"))?;
+    let source_ata = get_associated_token_address(&owner, &mint);
+    let dest_ata = get_associated_token_address(&dest, &mint);
+
+    if req.validate {
+        if let Some(rpc_url) = &state.rpc_url {
+            let client = RpcClient::new(rpc_url.clone());
+            assert_initialized_mint(&client, &mint).await?;
+            assert_initialized_token_account(&client, &source_ata).await?;
+            assert_initialized_token_account(&client, &dest_ata).await?;
+        }
+    }
+
+    let ix = token_instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_ata,
+        &dest_ata,
+        &owner,
+        &[],
+        req.amount,
+    )
+    .map_err(|_| error("Failed to create transfer instruction"))?;
 
     Ok(Json(SuccessResponse {
         success: true,
@@ -234,12 +390,320 @@ async fn send_token(
             "program_id": ix.program_id.to_string(),
             "accounts": ix.accounts,
             "instruction_data": general_purpose::STANDARD.encode(ix.data),
+            "source_ata": source_ata.to_string(),
+            "destination_ata": dest_ata.to_string(),
+        }),
+    }))
+}
+
+// 8. Create Associated Token Account
+#[derive(Deserialize)]
+struct CreateAtaReq {
+    funding_account: String,
+    wallet: String,
+    mint: String,
+}
+
+async fn create_ata(
+    Json(req): Json<CreateAtaReq>,
+) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
+    let funding_account = Pubkey::from_str(&req.funding_account)
+        .map_err(|_| error("Invalid funding account pubkey"))?;
+    let wallet = Pubkey::from_str(&req.wallet).map_err(|_| error("Invalid wallet pubkey"))?;
+    let mint = Pubkey::from_str(&req.mint).map_err(|_| error("Invalid mint pubkey"))?;
+
+    let ix = create_associated_token_account(&funding_account, &wallet, &mint, &TOKEN_PROGRAM_ID);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: serde_json::json!({
+            "program_id": ix.program_id.to_string(),
+            "accounts": ix.accounts,
+            "instruction_data": general_purpose::STANDARD.encode(ix.data),
+        }),
+    }))
+}
+
+// 9. Decode Instruction
+#[derive(Deserialize)]
+struct DecodeAccountMeta {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Deserialize)]
+struct DecodeInstructionReq {
+    program_id: String,
+    accounts: Vec<DecodeAccountMeta>,
+    instruction_data: String,
+}
+
+async fn decode_instruction(
+    Json(req): Json<DecodeInstructionReq>,
+) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
+    let program_id = Pubkey::from_str(&req.program_id).map_err(|_| error("Invalid program id"))?;
+    let data = general_purpose::STANDARD
+        .decode(&req.instruction_data)
+        .map_err(|_| error("Invalid base64 instruction data"))?;
+
+    let account_at = |index: usize| -> Result<String, Json<ErrorResponse>> {
+        req.accounts
+            .get(index)
+            .map(|a| a.pubkey.clone())
+            .ok_or_else(|| error("Instruction references more accounts than were provided"))
+    };
+
+    if program_id == TOKEN_PROGRAM_ID {
+        let instruction = TokenInstruction::unpack(&data)
+            .map_err(|_| error("Unknown or unparsable instruction"))?;
+
+        let decoded = match instruction {
+            TokenInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => serde_json::json!({
+                "type": "initializeMint",
+                "mint": account_at(0)?,
+                "decimals": decimals,
+                "mintAuthority": mint_authority.to_string(),
+                "freezeAuthority": Option::<Pubkey>::from(freeze_authority).map(|p| p.to_string()),
+                "rentSysvar": account_at(1)?,
+            }),
+            TokenInstruction::MintTo { amount } => serde_json::json!({
+                "type": "mintTo",
+                "mint": account_at(0)?,
+                "account": account_at(1)?,
+                "mintAuthority": account_at(2)?,
+                "amount": amount,
+            }),
+            TokenInstruction::Transfer { amount } => serde_json::json!({
+                "type": "transfer",
+                "source": account_at(0)?,
+                "destination": account_at(1)?,
+                "authority": account_at(2)?,
+                "amount": amount,
+            }),
+            _ => return Err(error("Unknown or unparsable instruction")),
+        };
+
+        return Ok(Json(SuccessResponse {
+            success: true,
+            data: decoded,
+        }));
+    }
+
+    if program_id == system_program::ID {
+        let instruction: SystemInstruction =
+            bincode::deserialize(&data).map_err(|_| error("Unknown or unparsable instruction"))?;
+
+        if let SystemInstruction::Transfer { lamports } = instruction {
+            return Ok(Json(SuccessResponse {
+                success: true,
+                data: serde_json::json!({
+                    "type": "transfer",
+                    "from": account_at(0)?,
+                    "to": account_at(1)?,
+                    "lamports": lamports,
+                }),
+            }));
+        }
+
+        return Err(error("Unknown or unparsable instruction"));
+    }
+
+    Err(error("Unknown or unparsable instruction"))
+}
+
+// 10. Build Transaction
+#[derive(Deserialize)]
+struct InstructionSpecAccount {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Deserialize)]
+struct InstructionSpec {
+    program_id: String,
+    accounts: Vec<InstructionSpecAccount>,
+    instruction_data: String,
+}
+
+#[derive(Deserialize)]
+struct BuildTransactionReq {
+    instructions: Vec<InstructionSpec>,
+    fee_payer: String,
+    recent_blockhash: String,
+    secrets: Option<Vec<String>>,
+}
+
+async fn build_transaction(
+    Json(req): Json<BuildTransactionReq>,
+) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
+    let fee_payer =
+        Pubkey::from_str(&req.fee_payer).map_err(|_| error("Invalid fee payer pubkey"))?;
+    let recent_blockhash =
+        Hash::from_str(&req.recent_blockhash).map_err(|_| error("Invalid recent blockhash"))?;
+
+    let mut instructions = Vec::with_capacity(req.instructions.len());
+    for spec in &req.instructions {
+        let program_id =
+            Pubkey::from_str(&spec.program_id).map_err(|_| error("Invalid program id"))?;
+        let data = general_purpose::STANDARD
+            .decode(&spec.instruction_data)
+            .map_err(|_| error("Invalid base64 instruction data"))?;
+
+        let mut accounts = Vec::with_capacity(spec.accounts.len());
+        for account in &spec.accounts {
+            let pubkey =
+                Pubkey::from_str(&account.pubkey).map_err(|_| error("Invalid account pubkey"))?;
+            accounts.push(AccountMeta {
+                pubkey,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            });
+        }
+
+        instructions.push(Instruction {
+            program_id,
+            accounts,
+            data,
+        });
+    }
+
+    let message = Message::new_with_blockhash(&instructions, Some(&fee_payer), &recent_blockhash);
+    let required_signers: Vec<String> = message.account_keys
+        [..message.header.num_required_signatures as usize]
+        .iter()
+        .map(|k| k.to_string())
+        .collect();
+
+    let secrets = req.secrets.unwrap_or_default();
+    if secrets.is_empty() {
+        let serialized_message =
+            bincode::serialize(&message).map_err(|_| error("Failed to serialize message"))?;
+        return Ok(Json(SuccessResponse {
+            success: true,
+            data: serde_json::json!({
+                "message": general_purpose::STANDARD.encode(serialized_message),
+                "missing_signers": required_signers,
+            }),
+        }));
+    }
+
+    let mut keypairs = Vec::with_capacity(secrets.len());
+    for secret in &secrets {
+        let bytes = secret
+            .from_base58()
+            .map_err(|_| error("Invalid secret format"))?;
+        let keypair = Keypair::from_bytes(&bytes).map_err(|_| error("Invalid secret key"))?;
+        keypairs.push(keypair);
+    }
+
+    let (known_signers, missing_signers) = partition_known_signers(keypairs, &required_signers);
+
+    let mut transaction = Transaction::new_unsigned(message);
+    let signer_refs: Vec<&Keypair> = known_signers.iter().collect();
+    transaction
+        .try_partial_sign(&signer_refs, recent_blockhash)
+        .map_err(|_| error("Failed to sign transaction"))?;
+
+    let serialized_transaction =
+        bincode::serialize(&transaction).map_err(|_| error("Failed to serialize transaction"))?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: serde_json::json!({
+            "transaction": general_purpose::STANDARD.encode(serialized_transaction),
+            "missing_signers": missing_signers,
+        }),
+    }))
+}
+
+// 11. Create NFT
+#[derive(Deserialize)]
+struct CreateNftReq {
+    mint: String,
+    mint_authority: String,
+    update_authority: String,
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+async fn create_nft(
+    Json(req): Json<CreateNftReq>,
+) -> Result<Json<SuccessResponse<Value>>, Json<ErrorResponse>> {
+    let mint = Pubkey::from_str(&req.mint).map_err(|_| error("Invalid mint pubkey"))?;
+    let mint_authority = Pubkey::from_str(&req.mint_authority)
+        .map_err(|_| error("Invalid mint authority pubkey"))?;
+    let update_authority = Pubkey::from_str(&req.update_authority)
+        .map_err(|_| error("Invalid update authority pubkey"))?;
+
+    let (metadata_pda, _) = Pubkey::find_program_address(
+        &[b"metadata", METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &METADATA_PROGRAM_ID,
+    );
+
+    let initialize_mint_ix =
+        token_instruction::initialize_mint(&TOKEN_PROGRAM_ID, &mint, &mint_authority, None, 0)
+            .map_err(|_| error("Failed to create initialize_mint instruction"))?;
+
+    let create_metadata_ix = create_metadata_accounts_v3(
+        METADATA_PROGRAM_ID,
+        metadata_pda,
+        mint,
+        mint_authority,
+        mint_authority,
+        update_authority,
+        req.name.clone(),
+        req.symbol.clone(),
+        req.uri.clone(),
+        None,
+        0,
+        true,
+        true,
+        None,
+        None,
+        None,
+    );
+
+    let destination_ata = get_associated_token_address(&mint_authority, &mint);
+    let create_ata_ix =
+        create_associated_token_account(&mint_authority, &mint_authority, &mint, &TOKEN_PROGRAM_ID);
+    let mint_to_ix = token_instruction::mint_to(
+        &TOKEN_PROGRAM_ID,
+        &mint,
+        &destination_ata,
+        &mint_authority,
+        &[],
+        1,
+    )
+    .map_err(|_| error("Failed to create mint_to instruction"))?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: serde_json::json!({
+            "instructions": [
+                instruction_to_json(&initialize_mint_ix),
+                instruction_to_json(&create_metadata_ix),
+                instruction_to_json(&create_ata_ix),
+                instruction_to_json(&mint_to_ix),
+            ],
+            "metadata": metadata_pda.to_string(),
+            "destination_ata": destination_ata.to_string(),
         }),
     }))
 }
 
 #[shuttle_runtime::main]
 async fn main() -> shuttle_axum::ShuttleAxum {
+    let state = Arc::new(AppState {
+        rpc_url: std::env::var("SOLANA_RPC_URL").ok(),
+    });
+
     let router = Router::new()
         .route("/", get(hello_world))
         .route("/keypair", post(generate_keypair))
@@ -248,11 +712,60 @@ async fn main() -> shuttle_axum::ShuttleAxum {
         .route("/message/sign", post(sign_message))
         .route("/message/verify", post(verify_message))
         .route("/send/sol", post(send_sol))
-        .route("/send/token", post(send_token));
+        .route("/send/token", post(send_token))
+        .route("/token/ata/create", post(create_ata))
+        .route("/instruction/decode", post(decode_instruction))
+        .route("/transaction/build", post(build_transaction))
+        .route("/nft/create", post(create_nft))
+        .with_state(state);
 
     Ok(router.into())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partition_known_signers_keeps_only_required_and_reports_missing() {
+        let signer_a = Keypair::new();
+        let signer_b = Keypair::new();
+        let unrelated = Keypair::new();
+        let signer_a_pubkey = signer_a.pubkey().to_string();
+        let signer_b_pubkey = signer_b.pubkey().to_string();
+        let required_signers = vec![signer_a_pubkey.clone(), signer_b_pubkey.clone()];
+
+        let (known, missing) =
+            partition_known_signers(vec![signer_a, unrelated], &required_signers);
+
+        assert_eq!(known.len(), 1);
+        assert_eq!(known[0].pubkey().to_string(), signer_a_pubkey);
+        assert_eq!(missing, vec![signer_b_pubkey]);
+    }
+
+    #[test]
+    fn partition_known_signers_with_no_secrets_reports_all_as_missing() {
+        let required_signers = vec![Keypair::new().pubkey().to_string()];
+
+        let (known, missing) = partition_known_signers(vec![], &required_signers);
+
+        assert!(known.is_empty());
+        assert_eq!(missing, required_signers);
+    }
+
+    #[test]
+    fn assert_rent_exempt_accepts_minimum_balance() {
+        let data_len = MintState::LEN;
+        let minimum_balance = Rent::default().minimum_balance(data_len);
+
+        assert!(assert_rent_exempt(minimum_balance, data_len).is_ok());
+    }
+
+    #[test]
+    fn assert_rent_exempt_rejects_underfunded_account() {
+        assert!(assert_rent_exempt(0, MintState::LEN).is_err());
+    }
+}
 
 // use axum::{routing::post, Json, Router};
 // use axum::http::StatusCode;
@@ -591,4 +1104,4 @@ async fn main() -> shuttle_axum::ShuttleAxum {
 //         .route("/send/token", post(send_token));
 
 //     Ok(router.into())
-// }
\ No newline at end of file
+// }